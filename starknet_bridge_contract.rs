@@ -16,11 +16,21 @@ trait IStarkNetIntentBridge<TContractState> {
     fn challenge_bundle(ref self: TContractState, bundle_root: felt252, invalid_fill: felt252, proof: Array<felt252>);
     fn execute_bundle(ref self: TContractState, bundle_root: felt252, solver_repayments: Array<(ContractAddress, u256)>);
     fn update_chain_state(ref self: TContractState, chain_id: u8, new_root: felt252, block_height: u64);
+    fn add_supported_token(ref self: TContractState, token: ContractAddress, min_amount: u256, max_amount: u256);
+    fn remove_supported_token(ref self: TContractState, token: ContractAddress);
+    fn is_token_supported(self: @TContractState, token: ContractAddress) -> bool;
+    fn get_token_limits(self: @TContractState, token: ContractAddress) -> (u256, u256);
+    fn set_guardian(ref self: TContractState, guardian: ContractAddress);
+    fn set_solver_approval(ref self: TContractState, solver: ContractAddress, approved: bool);
+    fn pause(ref self: TContractState);
+    fn unpause(ref self: TContractState);
+    fn is_paused(self: @TContractState) -> bool;
+    fn emergency_withdraw(ref self: TContractState, intent_hash: felt252);
 }
 
 #[starknet::contract]
 mod StarkNetIntentBridge {
-    use super::{ContractAddress, get_caller_address, get_block_timestamp};
+    use super::{ContractAddress, get_caller_address, get_block_timestamp, IERC20DispatcherTrait};
     use starknet::storage::{Map, StorageMapReadAccess, StorageMapWriteAccess, StoragePointerReadAccess, StoragePointerWriteAccess};
     use core::poseidon::poseidon_hash_span;
     use core::array::ArrayTrait;
@@ -30,11 +40,19 @@ mod StarkNetIntentBridge {
     const STARKNET_CHAIN: u8 = 2;
     const EVM_CHAIN: u8 = 3;
     const CHALLENGE_PERIOD: u64 = 3600;
+    const EMERGENCY_WITHDRAW_DELAY: u64 = 604800;
+    // A pause only restarts the emergency clock once the bridge has been
+    // unpaused for this long; a quicker re-pause resumes the previous clock.
+    const PAUSE_RESET_PERIOD: u64 = 604800;
 
     #[storage]
     struct Storage {
         owner: ContractAddress,
         dataworker: ContractAddress,
+        guardian: ContractAddress,
+        paused: bool,
+        paused_at: u64,
+        unpaused_at: u64,
         mina_state_root: felt252,
         zcash_state_root: felt252,
         evm_state_root: felt252,
@@ -47,6 +65,12 @@ mod StarkNetIntentBridge {
         approved_solvers: Map<ContractAddress, bool>,
         solver_balances: Map<ContractAddress, u256>,
         supported_tokens: Map<ContractAddress, bool>,
+        token_min_amount: Map<ContractAddress, u256>,
+        token_max_amount: Map<ContractAddress, u256>,
+        intent_user: Map<felt252, ContractAddress>,
+        intent_token: Map<felt252, ContractAddress>,
+        intent_amount: Map<felt252, u256>,
+        reclaimed_intents: Map<felt252, bool>,
     }
 
     #[event]
@@ -59,6 +83,13 @@ mod StarkNetIntentBridge {
         BundleDisputed: BundleDisputed,
         SolverRepaid: SolverRepaid,
         ChainStateUpdated: ChainStateUpdated,
+        TokenAdded: TokenAdded,
+        TokenRemoved: TokenRemoved,
+        GuardianUpdated: GuardianUpdated,
+        SolverApprovalUpdated: SolverApprovalUpdated,
+        Paused: Paused,
+        Unpaused: Unpaused,
+        EmergencyWithdrawn: EmergencyWithdrawn,
     }
 
     #[derive(Drop, starknet::Event)]
@@ -112,10 +143,55 @@ mod StarkNetIntentBridge {
         block_height: u64,
     }
 
+    #[derive(Drop, starknet::Event)]
+    struct TokenAdded {
+        token: ContractAddress,
+        min_amount: u256,
+        max_amount: u256,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct TokenRemoved {
+        token: ContractAddress,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct GuardianUpdated {
+        guardian: ContractAddress,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct SolverApprovalUpdated {
+        solver: ContractAddress,
+        approved: bool,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct Paused {
+        by: ContractAddress,
+        paused_at: u64,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct Unpaused {
+        by: ContractAddress,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct EmergencyWithdrawn {
+        intent_hash: felt252,
+        user: ContractAddress,
+        token: ContractAddress,
+        amount: u256,
+    }
+
     #[constructor]
     fn constructor(ref self: ContractState, owner: ContractAddress, dataworker: ContractAddress) {
         self.owner.write(owner);
         self.dataworker.write(dataworker);
+        self.paused.write(false);
+        self.paused_at.write(0);
+        self.unpaused_at.write(0);
         self.intent_nonce.write(0);
         self.total_volume.write(0);
         self.pending_bundle_root.write(0);
@@ -136,7 +212,10 @@ mod StarkNetIntentBridge {
             min_amount_out: u256,
             deadline: u64
         ) -> felt252 {
+            assert(!self.paused.read(), 'Bridge paused');
             assert(self.supported_tokens.read(token_in), 'Token not supported');
+            assert(amount_in >= self.token_min_amount.read(token_in), 'Amount below minimum');
+            assert(amount_in <= self.token_max_amount.read(token_in), 'Amount above maximum');
             assert(deadline > get_block_timestamp(), 'Deadline passed');
             assert(
                 target_chain == MINA_CHAIN || target_chain == ZCASH_CHAIN || target_chain == EVM_CHAIN,
@@ -147,7 +226,7 @@ mod StarkNetIntentBridge {
             let this = starknet::get_contract_address();
             
             let token_dispatcher = super::IERC20Dispatcher { contract_address: token_in };
-            token_dispatcher.transfer_from(caller, this, amount_in);
+            assert(token_dispatcher.transfer_from(caller, this, amount_in), 'Transfer failed');
 
             let nonce = self.intent_nonce.read();
             self.intent_nonce.write(nonce + 1);
@@ -167,6 +246,9 @@ mod StarkNetIntentBridge {
 
             let intent_hash = poseidon_hash_span(intent_data.span());
             self.processed_intents.write(intent_hash, true);
+            self.intent_user.write(intent_hash, caller);
+            self.intent_token.write(intent_hash, token_in);
+            self.intent_amount.write(intent_hash, amount_in);
 
             self.emit(IntentCreated {
                 intent_hash,
@@ -187,9 +269,17 @@ mod StarkNetIntentBridge {
             proof: Array<felt252>,
             fill_tx_hash: felt252
         ) {
+            if self.paused.read() {
+                assert(
+                    get_block_timestamp() < self.paused_at.read() + EMERGENCY_WITHDRAW_DELAY,
+                    'Fill window closed'
+                );
+            }
             assert(!self.processed_fills.read(intent_hash), 'Intent already filled');
+            assert(!self.reclaimed_intents.read(intent_hash), 'Intent reclaimed');
             
             let solver = get_caller_address();
+            assert(self.approved_solvers.read(solver), 'Solver not approved');
             let current_time = get_block_timestamp();
 
             self.processed_fills.write(intent_hash, true);
@@ -251,6 +341,7 @@ mod StarkNetIntentBridge {
             bundle_root: felt252,
             solver_repayments: Array<(ContractAddress, u256)>
         ) {
+            assert(!self.paused.read(), 'Bridge paused');
             assert(get_caller_address() == self.dataworker.read(), 'Not dataworker');
             assert(self.pending_bundle_root.read() == bundle_root, 'Bundle not pending');
             assert(get_block_timestamp() >= self.bundle_challenge_deadline.read(), 'Challenge period active');
@@ -306,6 +397,125 @@ mod StarkNetIntentBridge {
                 block_height,
             });
         }
+
+        fn add_supported_token(
+            ref self: ContractState,
+            token: ContractAddress,
+            min_amount: u256,
+            max_amount: u256
+        ) {
+            assert(get_caller_address() == self.owner.read(), 'Not owner');
+            let token_felt: felt252 = token.into();
+            assert(token_felt != 0, 'Invalid token');
+            assert(min_amount > 0, 'Invalid amount limits');
+            assert(min_amount <= max_amount, 'Invalid amount limits');
+
+            self.supported_tokens.write(token, true);
+            self.token_min_amount.write(token, min_amount);
+            self.token_max_amount.write(token, max_amount);
+
+            self.emit(TokenAdded {
+                token,
+                min_amount,
+                max_amount,
+            });
+        }
+
+        fn remove_supported_token(ref self: ContractState, token: ContractAddress) {
+            assert(get_caller_address() == self.owner.read(), 'Not owner');
+            assert(self.supported_tokens.read(token), 'Token not supported');
+
+            self.supported_tokens.write(token, false);
+            self.token_min_amount.write(token, 0);
+            self.token_max_amount.write(token, 0);
+
+            self.emit(TokenRemoved { token });
+        }
+
+        fn is_token_supported(self: @ContractState, token: ContractAddress) -> bool {
+            self.supported_tokens.read(token)
+        }
+
+        fn get_token_limits(self: @ContractState, token: ContractAddress) -> (u256, u256) {
+            (self.token_min_amount.read(token), self.token_max_amount.read(token))
+        }
+
+        fn set_guardian(ref self: ContractState, guardian: ContractAddress) {
+            assert(get_caller_address() == self.owner.read(), 'Not owner');
+            self.guardian.write(guardian);
+            self.emit(GuardianUpdated { guardian });
+        }
+
+        fn set_solver_approval(ref self: ContractState, solver: ContractAddress, approved: bool) {
+            assert(get_caller_address() == self.owner.read(), 'Not owner');
+            self.approved_solvers.write(solver, approved);
+            self.emit(SolverApprovalUpdated { solver, approved });
+        }
+
+        fn pause(ref self: ContractState) {
+            let caller = get_caller_address();
+            assert(
+                caller == self.guardian.read() || caller == self.owner.read(),
+                'Not guardian'
+            );
+            assert(!self.paused.read(), 'Already paused');
+
+            let now = get_block_timestamp();
+            let previous_paused_at = self.paused_at.read();
+            let paused_at = if previous_paused_at != 0
+                && now < self.unpaused_at.read() + PAUSE_RESET_PERIOD {
+                previous_paused_at
+            } else {
+                now
+            };
+            self.paused.write(true);
+            self.paused_at.write(paused_at);
+
+            self.emit(Paused { by: caller, paused_at });
+        }
+
+        fn unpause(ref self: ContractState) {
+            let caller = get_caller_address();
+            assert(caller == self.owner.read(), 'Not owner');
+            assert(self.paused.read(), 'Not paused');
+
+            self.paused.write(false);
+            self.unpaused_at.write(get_block_timestamp());
+
+            self.emit(Unpaused { by: caller });
+        }
+
+        fn is_paused(self: @ContractState) -> bool {
+            self.paused.read()
+        }
+
+        fn emergency_withdraw(ref self: ContractState, intent_hash: felt252) {
+            assert(self.paused.read(), 'Bridge not paused');
+            assert(
+                get_block_timestamp() >= self.paused_at.read() + EMERGENCY_WITHDRAW_DELAY,
+                'Emergency delay active'
+            );
+            assert(self.processed_intents.read(intent_hash), 'Unknown intent');
+            assert(!self.processed_fills.read(intent_hash), 'Intent already filled');
+            assert(!self.reclaimed_intents.read(intent_hash), 'Intent reclaimed');
+
+            let user = self.intent_user.read(intent_hash);
+            assert(get_caller_address() == user, 'Not intent owner');
+
+            let token = self.intent_token.read(intent_hash);
+            let amount = self.intent_amount.read(intent_hash);
+            self.reclaimed_intents.write(intent_hash, true);
+
+            let token_dispatcher = super::IERC20Dispatcher { contract_address: token };
+            assert(token_dispatcher.transfer(user, amount), 'Transfer failed');
+
+            self.emit(EmergencyWithdrawn {
+                intent_hash,
+                user,
+                token,
+                amount,
+            });
+        }
     }
 
     #[generate_trait]